@@ -1,141 +1,71 @@
-//! This module provides a template for solving Advent of Code-like puzzles in Rust.
-//! It includes utilities for reading input files, logging, and performance testing
-//! with `big-o-test`.
-//!
-//! The structure is designed to be easily adaptable for different daily challenges,
-//! separating concerns into input handling, part 1 and part 2 solutions, and a main
-//! function orchestrating the execution and performance analysis.
-
-use anyhow::{Context, Result};
-use log::LevelFilter;
-use simple_logger::SimpleLogger;
+use aoc::prelude::*;
 use std::collections::HashSet;
-use std::env;
-use std::fs;
-use std::path::PathBuf;
-
-/// Configuration for input file reading.
-///
-/// This struct currently serves as a namespace for input-related utility functions,
-/// specifically for determining the path to the puzzle input file.
-#[derive(Debug, Clone)]
-struct InputConfig;
 
-/// Implements input configuration extraction with more robust path handling.
-impl InputConfig {
-    fn input_path() -> Result<PathBuf> {
-        // Use CARGO_MANIFEST_DIR to get the package directory (challenges/2025/1/rs)
-        // Then go up one level to get to challenges/2025/1 and find input.txt
-        let manifest_dir = env!("CARGO_MANIFEST_DIR");
-        let input_path = PathBuf::from(manifest_dir)
-            .parent()
-            .context("Failed to get parent directory")?
-            .join("input.txt");
+/// Day 2: spotting "invalid" IDs built from a repeated digit pattern.
+pub struct Day2;
+
+impl Solution for Day2 {
+    const YEAR: u16 = 2025;
+    const DAY: u8 = 2;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    /// Invalid == the ID is a single pattern repeated exactly twice.
+    fn part1(input: &str) -> Result<Self::Answer1> {
+        fn is_invalid_id(id: u64) -> bool {
+            let s = id.to_string();
+            let n = s.len();
+            if n % 2 != 0 {
+                return false;
+            }
 
-        if input_path.exists() {
-            println!("Found input file at: {}", input_path.display());
-            return Ok(input_path);
+            let half_length = n / 2;
+            s[..half_length] == s[half_length..]
         }
 
-        Err(anyhow::anyhow!(
-            "Could not find input file. Searched path: {:?}",
-            input_path
-        ))
+        sum_invalid(input, is_invalid_id)
     }
-}
-
-/// Reads the puzzle input from a file with enhanced error handling.
-///
-/// This function leverages `InputConfig::input_path` to locate the input file
-/// and then reads its entire content into a `String`.
-///
-/// # Returns
-/// - `Ok(String)`: The content of the `input.txt` file.
-/// - `Err(anyhow::Error)`: If the input file cannot be found or read.
-fn read_input() -> Result<String> {
-    let input_path = InputConfig::input_path()?;
-
-    fs::read_to_string(&input_path)
-        .with_context(|| format!("Failed to read input from {:?}", input_path))
-}
 
-/// Solves part 1 of the puzzle.
-///
-/// This function takes the puzzle input as a string slice and should return
-/// the solution for Part 1.
-///
-/// # Arguments
-/// * `input` - A string slice containing the puzzle input.
-///
-/// # Returns
-/// The solution for Part 1 as a `u32`.
-///
-/// # TODO
-/// Implement the actual logic for Part 1 of the puzzle.
-fn part1(input: &str) -> u64 {
-    fn is_invalid_id(id: u64) -> bool {
-        let s = id.to_string();
-        let n = s.len();
-        if n % 2 != 0 {
-            return false;
-        }
-
-        let half_length = n / 2;
-        return &s[..half_length] == &s[half_length..];
-    }
-    let mut invalid_ids: HashSet<u64> = HashSet::new();
-    for line in input.lines() {
-        for c in line.split(',') {
-            let parts: Vec<u64> = c.split('-').map(|s| s.parse::<u64>().unwrap()).collect();
-            let [start, end]: [u64; 2] = parts.try_into().unwrap();
-            for i in start..=end {
-                if is_invalid_id(i) {
-                    invalid_ids.insert(i);
+    /// Invalid == the ID is any pattern repeated two or more times.
+    fn part2(input: &str) -> Result<Self::Answer2> {
+        fn is_invalid_id(id: u64) -> bool {
+            let s = id.to_string();
+            let n = s.len();
+
+            // An ID is invalid if it is made only of some sequence of digits repeated at least twice.
+            // E.g., 12341234 (1234 two times), 123123123 (123 three times), 1212121212 (12 five times),
+            // and 1111111 (1 seven times) are all invalid IDs.
+            for len in 1..=n / 2 {
+                if n % len == 0 {
+                    let pattern = &s[..len];
+                    let repeated = pattern.repeat(n / len);
+                    if repeated == s {
+                        return true;
+                    }
                 }
             }
+            false
         }
-    }
-    return invalid_ids.iter().sum();
-}
 
-/// Solves part 2 of the puzzle.
-///
-/// This function takes the puzzle input as a string slice and should return
-/// the solution for Part 2.
-///
-/// # Arguments
-/// * `input` - A string slice containing the puzzle input.
-///
-/// # Returns
-/// The solution for Part 2 as a `u32`.
-///
-/// # TODO
-/// Implement the actual logic for Part 2 of the puzzle.
-fn part2(input: &str) -> u64 {
-    fn is_invalid_id(id: u64) -> bool {
-        let s = id.to_string();
-        let n = s.len();
-
-        // An ID is invalid if it is made only of some sequence of digits repeated at least twice.
-        // E.g., 12341234 (1234 two times), 123123123 (123 three times), 1212121212 (12 five times),
-        // and 1111111 (1 seven times) are all invalid IDs.
-        for len in 1..=n / 2 {
-            if n % len == 0 {
-                let pattern = &s[..len];
-                let repeated = pattern.repeat(n / len);
-                if repeated == s {
-                    return true;
-                }
-            }
-        }
-        false
+        sum_invalid(input, is_invalid_id)
     }
+}
 
+/// Sums the distinct invalid IDs across every `start-end` range in the input.
+fn sum_invalid(input: &str, is_invalid_id: fn(u64) -> bool) -> Result<u64> {
     let mut invalid_ids: HashSet<u64> = HashSet::new();
     for line in input.lines() {
         for c in line.split(',') {
-            let parts: Vec<u64> = c.split('-').map(|s| s.parse::<u64>().unwrap()).collect();
-            let [start, end]: [u64; 2] = parts.try_into().unwrap();
+            let parts: Vec<u64> = c
+                .split('-')
+                .map(|s| {
+                    s.parse::<u64>()
+                        .with_context(|| format!("invalid id bound {s:?} in range {c:?}"))
+                })
+                .collect::<Result<_>>()?;
+            let [start, end]: [u64; 2] = parts
+                .try_into()
+                .map_err(|_| anyhow::anyhow!("expected a `start-end` range, got {c:?}"))?;
             for i in start..=end {
                 if is_invalid_id(i) {
                     invalid_ids.insert(i);
@@ -143,66 +73,27 @@ fn part2(input: &str) -> u64 {
             }
         }
     }
-    return invalid_ids.iter().sum();
+    Ok(invalid_ids.iter().sum())
 }
 
-/// Main entry point for the program.
-///
-/// This function orchestrates the execution of the puzzle solution:
-/// 1. Initializes logging.
-/// 2. Reads the puzzle input.
-/// 3. Runs performance tests for Part 1 and Part 2 using `big-o-test`.
-/// 4. Prints the final solutions for Part 1 and Part 2.
-///
-/// # Returns
-/// - `Ok(())`: If the program executes successfully.
-/// - `Err(anyhow::Error)`: If any step (logging, input reading) fails.
-fn main() -> Result<()> {
-    // Initialize logging with better error handling
-    SimpleLogger::new()
-        .with_level(LevelFilter::Info)
-        .init()
-        .context("Logging initialization failed")?;
-
-    // Read input with early validation
-    let input = read_input()?;
-
-    println!("Part 1: {}", part1(&input));
-    println!("Part 2: {}", part2(&input));
-
-    Ok(())
-}
+aoc_main!(Day2);
 
 /// Integration tests module for verifying the correctness of the puzzle solutions.
 #[cfg(test)]
 mod tests {
     use super::*;
 
-    /// Tests the `part1` function.
-    ///
-    /// This test reads the input file and asserts that the result of `part1`
-    /// is greater than 0, indicating a non-trivial solution.
-    ///
-    /// # Panics
-    /// If input reading fails or if the `part1` solution is not greater than 0.
     #[test]
     fn test_part1() {
-        let input = read_input().expect("Input reading failed");
-        let result = part1(&input);
-        assert!(result > 0, "Part 1 solution must be non-negative");
+        let input = read_example(env!("CARGO_MANIFEST_DIR"), "example1").expect("Input reading failed");
+        let result = Day2::part1(&input).expect("part 1 failed");
+        assert_eq!(result, 11);
     }
 
-    /// Tests the `part2` function.
-    ///
-    /// This test reads the input file and asserts that the result of `part2`
-    /// is greater than 0, indicating a non-trivial solution.
-    ///
-    /// # Panics
-    /// If input reading fails or if the `part2` solution is not greater than 0.
     #[test]
     fn test_part2() {
-        let input = read_input().expect("Input reading failed");
-        let result = part2(&input);
-        assert!(result > 0, "Part 2 solution must be non-negative");
+        let input = read_example(env!("CARGO_MANIFEST_DIR"), "example1").expect("Input reading failed");
+        let result = Day2::part2(&input).expect("part 2 failed");
+        assert_eq!(result, 121223);
     }
 }
@@ -0,0 +1,248 @@
+//! A benchmarking harness that makes the "performance testing with `big-o-test`"
+//! promise in the module docs actually true.
+//!
+//! [`bench`] times a closure `runs` times and reports min/mean/median
+//! wall-clock. [`measure`] times a solver over caller-supplied inputs of
+//! increasing size, and [`classify`] estimates its complexity class from those
+//! `(size, time)` points; no synthetic inputs are generated here. The runner
+//! exposes [`bench`] behind `--bench`; it prints timings only and does not
+//! estimate complexity.
+
+use anyhow::{bail, Result};
+use std::time::{Duration, Instant};
+
+/// Summary statistics for a set of timed runs.
+#[derive(Debug, Clone, Copy)]
+pub struct Timings {
+    /// Fastest observed run.
+    pub min: Duration,
+    /// Arithmetic mean across runs.
+    pub mean: Duration,
+    /// Median run (50th percentile).
+    pub median: Duration,
+    /// Number of runs measured.
+    pub runs: usize,
+}
+
+/// Runs `f` `runs` times, discarding its output, and returns timing statistics.
+///
+/// `runs` is clamped to at least 1 so a caller can't ask for an empty sample.
+pub fn bench<F, T>(runs: usize, mut f: F) -> Timings
+where
+    F: FnMut() -> T,
+{
+    let runs = runs.max(1);
+    let mut samples: Vec<Duration> = Vec::with_capacity(runs);
+    for _ in 0..runs {
+        let start = Instant::now();
+        let out = f();
+        samples.push(start.elapsed());
+        // Keep the optimizer from eliding the work we just timed.
+        std::hint::black_box(out);
+    }
+
+    samples.sort_unstable();
+    let min = samples[0];
+    let median = samples[samples.len() / 2];
+    let total: Duration = samples.iter().sum();
+    let mean = total / runs as u32;
+
+    Timings { min, mean, median, runs }
+}
+
+/// An empirically estimated complexity class, ordered from cheapest to most
+/// expensive.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Complexity {
+    /// Constant time.
+    O1,
+    /// Logarithmic.
+    OLogN,
+    /// Linear.
+    ON,
+    /// Linearithmic.
+    ONLogN,
+    /// Quadratic.
+    ON2,
+    /// Cubic or worse.
+    ON3,
+}
+
+impl Complexity {
+    /// The reference growth function f(n) used for ratio fitting.
+    fn f(self, n: f64) -> f64 {
+        match self {
+            Complexity::O1 => 1.0,
+            Complexity::OLogN => n.max(2.0).log2(),
+            Complexity::ON => n,
+            Complexity::ONLogN => n * n.max(2.0).log2(),
+            Complexity::ON2 => n * n,
+            Complexity::ON3 => n * n * n,
+        }
+    }
+
+    /// All classes, cheapest first.
+    pub fn all() -> [Complexity; 6] {
+        [
+            Complexity::O1,
+            Complexity::OLogN,
+            Complexity::ON,
+            Complexity::ONLogN,
+            Complexity::ON2,
+            Complexity::ON3,
+        ]
+    }
+}
+
+impl std::fmt::Display for Complexity {
+    fn fmt(&self, out: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let label = match self {
+            Complexity::O1 => "O(1)",
+            Complexity::OLogN => "O(log n)",
+            Complexity::ON => "O(n)",
+            Complexity::ONLogN => "O(n log n)",
+            Complexity::ON2 => "O(n²)",
+            Complexity::ON3 => "O(n³)",
+        };
+        out.write_str(label)
+    }
+}
+
+/// Estimates the complexity class that best explains `(size, time)` points.
+///
+/// For each candidate class f(n) we compute the scaling constants
+/// cᵢ = tᵢ / f(nᵢ) and measure their coefficient of variation; the lowest-order
+/// class whose constants stay roughly flat wins. Returns `None` if fewer than
+/// two points are supplied.
+pub fn classify(points: &[(usize, Duration)]) -> Option<Complexity> {
+    if points.len() < 2 {
+        return None;
+    }
+
+    let mut best: Option<(Complexity, f64)> = None;
+    for class in Complexity::all() {
+        let constants: Vec<f64> = points
+            .iter()
+            .map(|(n, t)| t.as_secs_f64() / class.f(*n as f64))
+            .collect();
+        let mean = constants.iter().sum::<f64>() / constants.len() as f64;
+        if mean == 0.0 {
+            continue;
+        }
+        let variance = constants
+            .iter()
+            .map(|c| (c - mean).powi(2))
+            .sum::<f64>()
+            / constants.len() as f64;
+        let cv = variance.sqrt() / mean;
+
+        // Prefer the cheapest class whose constants are flat (low CV); `all()`
+        // is ordered cheapest-first, so keep the first sufficiently flat fit.
+        if cv < 0.25 {
+            return Some(class);
+        }
+        if best.as_ref().is_none_or(|&(_, best_cv)| cv < best_cv) {
+            best = Some((class, cv));
+        }
+    }
+
+    best.map(|(class, _)| class)
+}
+
+/// Runs `f` against each input in `inputs`, returning `(input_len, median_time)`
+/// points. Each size is timed `reps` times and the median is kept to reduce
+/// noise.
+pub fn measure<F, T>(f: &F, inputs: &[String], reps: usize) -> Vec<(usize, Duration)>
+where
+    F: Fn(&str) -> T,
+{
+    inputs
+        .iter()
+        .map(|input| {
+            let timings = bench(reps, || f(input));
+            (input.len(), timings.median)
+        })
+        .collect()
+}
+
+/// Empirically verifies that `f` stays within the declared `bound`.
+///
+/// Times `f` across the increasingly-sized `inputs` (median of a few
+/// repetitions per size), classifies the observed growth via [`classify`], and
+/// returns the measured [`Complexity`]. Errors if the measured class exceeds
+/// `bound`, so a day can assert its parts stay within budget:
+///
+/// ```ignore
+/// assert_complexity(|i| Day9::part1(i).unwrap(), Complexity::ON2, &samples).unwrap();
+/// ```
+pub fn assert_complexity<F, T>(f: F, bound: Complexity, inputs: &[String]) -> Result<Complexity>
+where
+    F: Fn(&str) -> T,
+{
+    if inputs.len() < 2 {
+        bail!("assert_complexity needs at least two input sizes, got {}", inputs.len());
+    }
+
+    let points = measure(&f, inputs, 5);
+    let measured = classify(&points)
+        .ok_or_else(|| anyhow::anyhow!("could not classify complexity from timings"))?;
+
+    if measured > bound {
+        bail!("measured complexity {measured} exceeds declared bound {bound}");
+    }
+    Ok(measured)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn bench_reports_sorted_stats() {
+        let t = bench(5, || (0..1000).sum::<u64>());
+        assert_eq!(t.runs, 5);
+        assert!(t.min <= t.median);
+    }
+
+    #[test]
+    fn classify_detects_linear_growth() {
+        // Synthetic perfectly-linear timings.
+        let points = [
+            (100, Duration::from_micros(100)),
+            (200, Duration::from_micros(200)),
+            (400, Duration::from_micros(400)),
+            (800, Duration::from_micros(800)),
+        ];
+        assert_eq!(classify(&points), Some(Complexity::ON));
+    }
+
+    #[test]
+    fn classify_detects_quadratic_growth() {
+        let points = [
+            (100, Duration::from_micros(100)),
+            (200, Duration::from_micros(400)),
+            (400, Duration::from_micros(1600)),
+            (800, Duration::from_micros(6400)),
+        ];
+        assert_eq!(classify(&points), Some(Complexity::ON2));
+    }
+
+    #[test]
+    fn assert_complexity_passes_within_budget() {
+        // A linear pass over the input, exercised at a few sizes.
+        let inputs: Vec<String> = [100, 200, 400, 800]
+            .iter()
+            .map(|&n| "a".repeat(n))
+            .collect();
+        let measured =
+            assert_complexity(|s: &str| s.bytes().map(|b| b as u64).sum::<u64>(), Complexity::ON, &inputs)
+                .unwrap();
+        assert!(measured <= Complexity::ON);
+    }
+
+    #[test]
+    fn assert_complexity_rejects_too_few_inputs() {
+        let inputs = vec!["x".to_string()];
+        assert!(assert_complexity(|s: &str| s.len(), Complexity::ON, &inputs).is_err());
+    }
+}
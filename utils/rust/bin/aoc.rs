@@ -0,0 +1,121 @@
+//! Central runner binary: dispatches any subset of days through the shared
+//! [`Registry`]. Each day is registered once here instead of shipping its own
+//! `main`.
+//!
+//! ```text
+//! aoc run                     # run every registered day (verb optional)
+//! aoc run -y 2025 -d 1..=25
+//! aoc run -d 1,3,7
+//! aoc watch --year 2025 --day 1   # re-run one day on each save
+//! aoc --bench --runs 200          # time each part and report min/mean/median
+//! ```
+#![allow(dead_code)]
+
+use aoc::runner::{self, DaySpec, Registry};
+use anyhow::{Context, Result};
+
+// Pull each day's `Solution` impl into scope. These live next to their puzzle
+// inputs under `challenges/`, so we reference them by path.
+#[path = "../../../challenges/2025/1/rs/index.rs"]
+mod day1;
+#[path = "../../../challenges/2025/2/rs/index.rs"]
+mod day2;
+#[path = "../../../challenges/2025/3/rs/index.rs"]
+mod day3;
+#[path = "../../../challenges/2025/4/rs/index.rs"]
+mod day4;
+#[path = "../../../challenges/2025/5/rs/index.rs"]
+mod day5;
+#[path = "../../../challenges/2025/6/rs/index.rs"]
+mod day6;
+#[path = "../../../challenges/2025/7/rs/index.rs"]
+mod day7;
+#[path = "../../../challenges/2025/9/rs/index.rs"]
+mod day9;
+
+/// Builds the registry of every known day, keyed by `(year, day)`.
+fn registry() -> Registry {
+    let mut reg = Registry::new();
+    reg.register::<day1::Day1>()
+        .register::<day2::Day2>()
+        .register::<day3::Day3>()
+        .register::<day4::Day4>()
+        .register::<day5::Day5>()
+        .register::<day6::Day6>()
+        .register::<day7::Day7>()
+        .register::<day9::Day9>();
+    reg
+}
+
+fn main() -> Result<()> {
+    let mut args: Vec<String> = std::env::args().skip(1).collect();
+    match args.first().map(String::as_str) {
+        Some("watch") => {
+            args.remove(0);
+            return watch(&args);
+        }
+        // `aoc run ...` is the explicit verb; a bare `aoc ...` runs the same
+        // path so the tool works with or without it.
+        Some("run") => {
+            args.remove(0);
+        }
+        _ => {}
+    }
+
+    let mut year: Option<u16> = None;
+    let mut spec = DaySpec::All;
+    let mut bench_runs: Option<usize> = None;
+
+    let mut args = args.into_iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--year" | "-y" => {
+                let value = args.next().context("--year expects a value")?;
+                year = Some(value.parse().with_context(|| format!("bad year {value:?}"))?);
+            }
+            "--days" | "-d" => {
+                let value = args.next().context("--days expects a value")?;
+                spec = DaySpec::parse(&value)?;
+            }
+            "--bench" => {
+                // Run count comes from `--runs`, defaulting to 100.
+                bench_runs = Some(bench_runs.unwrap_or(100));
+            }
+            "--runs" => {
+                let value = args.next().context("--runs expects a value")?;
+                bench_runs = Some(value.parse().with_context(|| format!("bad run count {value:?}"))?);
+            }
+            other => return Err(anyhow::anyhow!("unknown argument {other:?}")),
+        }
+    }
+
+    match bench_runs {
+        Some(runs) => registry().bench(year, &spec, runs),
+        None => registry().run(year, &spec),
+    }
+}
+
+/// Handles `aoc watch --year <y> --day <d>`.
+fn watch(args: &[String]) -> Result<()> {
+    let mut year: Option<u16> = None;
+    let mut day: Option<u8> = None;
+
+    let mut it = args.iter();
+    while let Some(arg) = it.next() {
+        match arg.as_str() {
+            "--year" | "-y" => {
+                let value = it.next().context("--year expects a value")?;
+                year = Some(value.parse().with_context(|| format!("bad year {value:?}"))?);
+            }
+            "--day" | "-d" => {
+                let value = it.next().context("--day expects a value")?;
+                day = Some(value.parse().with_context(|| format!("bad day {value:?}"))?);
+            }
+            other => return Err(anyhow::anyhow!("unknown argument {other:?}")),
+        }
+    }
+
+    let year = year.context("watch requires --year")?;
+    let day = day.context("watch requires --day")?;
+    runner::watch(&registry(), year, day)
+}
@@ -0,0 +1,153 @@
+//! Reusable graph traversals generic over a node type.
+//!
+//! The day solutions already hand-build adjacency `HashMap`s and run memoized
+//! DFS (see the part-2 polygon and grid-DAG puzzles). These helpers lift that
+//! into the shared `dsa` module: successors are supplied as closures so the
+//! same traversal serves both grid graphs and abstract ones.
+
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap, VecDeque};
+use std::hash::Hash;
+
+/// Cost type used by [`dijkstra`]. Kept concrete (`u64`) to match the day
+/// solutions, which tally joltage/area counts as `u64`.
+pub type Cost = u64;
+
+/// Breadth-first search from `start`, returning the shortest edge-count
+/// distance to every reachable node.
+///
+/// `successors` yields the neighbors of a node. `start` maps to distance `0`.
+pub fn bfs<N, S>(start: N, successors: S) -> HashMap<N, usize>
+where
+    N: Eq + Hash + Clone,
+    S: Fn(&N) -> Vec<N>,
+{
+    let mut dist: HashMap<N, usize> = HashMap::new();
+    let mut queue: VecDeque<N> = VecDeque::new();
+    dist.insert(start.clone(), 0);
+    queue.push_back(start);
+
+    while let Some(node) = queue.pop_front() {
+        let d = dist[&node];
+        for next in successors(&node) {
+            if !dist.contains_key(&next) {
+                dist.insert(next.clone(), d + 1);
+                queue.push_back(next);
+            }
+        }
+    }
+
+    dist
+}
+
+/// Dijkstra's algorithm from `start`, returning the best (lowest) cost to every
+/// reachable node.
+///
+/// `successors` yields `(neighbor, edge_cost)` pairs. The frontier is a
+/// min-heap (`BinaryHeap<Reverse<(Cost, N)>>`); popped entries stale against the
+/// recorded best are skipped before relaxing neighbors.
+pub fn dijkstra<N, S>(start: N, successors: S) -> HashMap<N, Cost>
+where
+    N: Eq + Hash + Clone + Ord,
+    S: Fn(&N) -> Vec<(N, Cost)>,
+{
+    let mut best: HashMap<N, Cost> = HashMap::new();
+    let mut heap: BinaryHeap<Reverse<(Cost, N)>> = BinaryHeap::new();
+    best.insert(start.clone(), 0);
+    heap.push(Reverse((0, start)));
+
+    while let Some(Reverse((cost, node))) = heap.pop() {
+        // Skip entries that a cheaper path has already superseded.
+        if cost > best[&node] {
+            continue;
+        }
+        for (next, edge) in successors(&node) {
+            let candidate = cost + edge;
+            if best.get(&next).is_none_or(|&c| candidate < c) {
+                best.insert(next.clone(), candidate);
+                heap.push(Reverse((candidate, next)));
+            }
+        }
+    }
+
+    best
+}
+
+/// Counts the number of distinct paths from `start` to any goal node in a DAG,
+/// with internal memoization.
+///
+/// `is_goal` marks terminal nodes (each contributes one path); `successors`
+/// yields the onward nodes. The graph must be acyclic — cycles would recurse
+/// forever, matching the assumptions of the DAG path-counting puzzles.
+pub fn count_paths<N, G, S>(start: N, is_goal: G, successors: S) -> u64
+where
+    N: Eq + Hash + Clone,
+    G: Fn(&N) -> bool,
+    S: Fn(&N) -> Vec<N>,
+{
+    fn walk<N, G, S>(
+        node: &N,
+        is_goal: &G,
+        successors: &S,
+        memo: &mut HashMap<N, u64>,
+    ) -> u64
+    where
+        N: Eq + Hash + Clone,
+        G: Fn(&N) -> bool,
+        S: Fn(&N) -> Vec<N>,
+    {
+        if is_goal(node) {
+            return 1;
+        }
+        if let Some(&cached) = memo.get(node) {
+            return cached;
+        }
+        let total = successors(node)
+            .iter()
+            .map(|next| walk(next, is_goal, successors, memo))
+            .sum();
+        memo.insert(node.clone(), total);
+        total
+    }
+
+    let mut memo: HashMap<N, u64> = HashMap::new();
+    walk(&start, &is_goal, &successors, &mut memo)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // A tiny directed graph: 0 -> 1 -> 3, 0 -> 2 -> 3.
+    fn succ(n: &u32) -> Vec<u32> {
+        match n {
+            0 => vec![1, 2],
+            1 | 2 => vec![3],
+            _ => vec![],
+        }
+    }
+
+    #[test]
+    fn bfs_distances() {
+        let dist = bfs(0u32, succ);
+        assert_eq!(dist[&0], 0);
+        assert_eq!(dist[&1], 1);
+        assert_eq!(dist[&3], 2);
+    }
+
+    #[test]
+    fn dijkstra_picks_cheapest() {
+        // 0 -(1)-> 1 -(5)-> 2, and 0 -(2)-> 2 directly.
+        let best = dijkstra(0u32, |n| match n {
+            0 => vec![(1, 1), (2, 2)],
+            1 => vec![(2, 5)],
+            _ => vec![],
+        });
+        assert_eq!(best[&2], 2);
+    }
+
+    #[test]
+    fn count_paths_counts_dag_routes() {
+        assert_eq!(count_paths(0u32, |n| *n == 3, succ), 2);
+    }
+}
@@ -0,0 +1,5 @@
+pub mod graph;
+pub mod queue;
+
+pub use graph::*;
+pub use queue::*;
@@ -0,0 +1,101 @@
+//! Automatic puzzle-input acquisition with session-token caching.
+//!
+//! A missing `input.txt` used to be an unrecoverable hard error. This layer
+//! downloads the input for a `(year, day)` from the Advent of Code website the
+//! first time it's needed — authenticating with a session token kept outside
+//! the repository — and caches it to the expected path so later runs are
+//! offline. A cached copy is never re-downloaded, which keeps us within the
+//! site's request etiquette.
+
+use anyhow::{bail, Context, Result};
+use std::path::Path;
+use std::time::Duration;
+
+/// Environment variable holding the session token directly.
+const SESSION_ENV: &str = "AOC_SESSION";
+/// Environment variable pointing at a file containing the session token.
+const SESSION_FILE_ENV: &str = "AOC_SESSION_FILE";
+
+/// Reads the Advent of Code session token from the environment or a config
+/// file, never from inside the repository.
+///
+/// Checks `AOC_SESSION` first, then the file named by `AOC_SESSION_FILE`.
+pub fn session_token() -> Result<String> {
+    if let Ok(token) = std::env::var(SESSION_ENV) {
+        let token = token.trim().to_string();
+        if !token.is_empty() {
+            return Ok(token);
+        }
+    }
+
+    if let Ok(path) = std::env::var(SESSION_FILE_ENV) {
+        let token = std::fs::read_to_string(&path)
+            .with_context(|| format!("failed to read session token from {path:?}"))?;
+        let token = token.trim().to_string();
+        if !token.is_empty() {
+            return Ok(token);
+        }
+    }
+
+    bail!(
+        "no Advent of Code session token found; set {SESSION_ENV} or point {SESSION_FILE_ENV} at a token file"
+    )
+}
+
+/// Ensures the input for `(year, day)` exists at `path`, downloading and caching
+/// it if absent.
+///
+/// If `path` already exists it is read as-is and no request is made (cached
+/// copies are never re-downloaded). Otherwise the input is fetched using the
+/// session token and written to `path` for subsequent offline runs.
+pub fn ensure_input(year: u16, day: u8, path: &Path) -> Result<String> {
+    if path.exists() {
+        return std::fs::read_to_string(path)
+            .with_context(|| format!("Failed to read cached input from {path:?}"));
+    }
+
+    let token = session_token()?;
+    let body = download(year, day, &token)?;
+
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)
+            .with_context(|| format!("failed to create input directory {parent:?}"))?;
+    }
+    std::fs::write(path, &body)
+        .with_context(|| format!("failed to cache input to {path:?}"))?;
+
+    Ok(body)
+}
+
+/// Downloads the raw input for `(year, day)`, authenticating with `token`.
+fn download(year: u16, day: u8, token: &str) -> Result<String> {
+    let url = format!("https://adventofcode.com/{year}/day/{day}/input");
+
+    let client = reqwest::blocking::Client::builder()
+        .timeout(Duration::from_secs(30))
+        .user_agent(concat!(
+            "advent-of-code runner (",
+            env!("CARGO_PKG_REPOSITORY"),
+            ")"
+        ))
+        .build()
+        .context("failed to build HTTP client")?;
+
+    let response = client
+        .get(&url)
+        .header("Cookie", format!("session={token}"))
+        .send()
+        .with_context(|| format!("request to {url} failed"))?;
+
+    let status = response.status();
+    if status == reqwest::StatusCode::BAD_REQUEST || status == reqwest::StatusCode::UNAUTHORIZED {
+        bail!("authentication failed ({status}); the session token is missing, expired, or invalid");
+    }
+    if !status.is_success() {
+        bail!("unexpected response {status} fetching {url}");
+    }
+
+    response
+        .text()
+        .with_context(|| format!("failed to read response body from {url}"))
+}
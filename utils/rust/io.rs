@@ -0,0 +1,146 @@
+//! Typed input parsing helpers that replace ad-hoc `split(',')` + `unwrap()`.
+//!
+//! Hand-rolled parsing in the day files (`parts[0].parse().unwrap()`) panics on
+//! malformed input with an opaque message. These helpers funnel every day
+//! through a single typed entry point and attach line/field context via
+//! [`anyhow::Context`], so a bad line reports its number and content:
+//!
+//! ```ignore
+//! // challenges/.../9: one `x,y` point per line
+//! let points: Vec<Point> = io::parse_lines(input)?;
+//!
+//! // blocks separated by blank lines (the common AoC `"\n\n"` pattern)
+//! let groups: Vec<Group> = io::parse_blocks(input)?;
+//!
+//! // a character grid plus its dimensions
+//! let (grid, (rows, cols)) = io::parse_grid(input);
+//! ```
+
+use anyhow::{Context, Result};
+use std::str::FromStr;
+
+/// Parses each non-empty line of `input` into a `T`, reporting the 1-based line
+/// number and its content on failure.
+pub fn parse_lines<T>(input: &str) -> Result<Vec<T>>
+where
+    T: FromStr,
+    T::Err: std::fmt::Display,
+{
+    input
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty())
+        .map(|(i, line)| {
+            line.parse::<T>()
+                .map_err(|e| anyhow::anyhow!("{e}"))
+                .with_context(|| format!("line {}: {line:?}", i + 1))
+        })
+        .collect()
+}
+
+/// Parses blocks separated by blank lines (the `"\n\n"` pattern) into `T`,
+/// reporting the 1-based block number on failure.
+pub fn parse_blocks<T>(input: &str) -> Result<Vec<T>>
+where
+    T: FromStr,
+    T::Err: std::fmt::Display,
+{
+    input
+        .split("\n\n")
+        .map(|block| block.trim_matches('\n'))
+        .filter(|block| !block.trim().is_empty())
+        .enumerate()
+        .map(|(i, block)| {
+            block
+                .parse::<T>()
+                .map_err(|e| anyhow::anyhow!("{e}"))
+                .with_context(|| format!("block {}: {block:?}", i + 1))
+        })
+        .collect()
+}
+
+/// Parses `input` into a character grid and returns it alongside its
+/// `(rows, cols)` dimensions. `cols` is the width of the widest row.
+pub fn parse_grid(input: &str) -> (Vec<Vec<char>>, (usize, usize)) {
+    let grid: Vec<Vec<char>> = input
+        .lines()
+        .filter(|line| !line.is_empty())
+        .map(|line| line.chars().collect())
+        .collect();
+    let rows = grid.len();
+    let cols = grid.iter().map(|row| row.len()).max().unwrap_or(0);
+    (grid, (rows, cols))
+}
+
+/// Splits a single line into exactly `N` fields on `sep`, parsing each into `T`
+/// and reporting which field failed.
+///
+/// Handy for the `"x,y"` coordinate lines that previously used
+/// `parts[0].parse().unwrap()`.
+pub fn parse_fields<T, const N: usize>(line: &str, sep: char) -> Result<[T; N]>
+where
+    T: FromStr,
+    T::Err: std::fmt::Display,
+{
+    let parts: Vec<&str> = line.split(sep).collect();
+    if parts.len() != N {
+        return Err(anyhow::anyhow!(
+            "expected {N} fields separated by {sep:?}, got {} in {line:?}",
+            parts.len()
+        ));
+    }
+    let mut out: Vec<T> = Vec::with_capacity(N);
+    for (i, part) in parts.iter().enumerate() {
+        let value = part
+            .trim()
+            .parse::<T>()
+            .map_err(|e| anyhow::anyhow!("{e}"))
+            .with_context(|| format!("field {} ({part:?}) in {line:?}", i + 1))?;
+        out.push(value);
+    }
+    Ok(out
+        .try_into()
+        .unwrap_or_else(|_| unreachable!("length checked above")))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_lines_reports_bad_line() {
+        let err = parse_lines::<i64>("1\n2\nx\n4").unwrap_err();
+        assert!(err.to_string().contains("line 3"));
+    }
+
+    #[test]
+    fn parse_lines_skips_blanks() {
+        let nums: Vec<i64> = parse_lines("1\n\n2\n").unwrap();
+        assert_eq!(nums, vec![1, 2]);
+    }
+
+    #[test]
+    fn parse_blocks_splits_on_blank_lines() {
+        let blocks: Vec<String> = parse_blocks("a\nb\n\nc").unwrap();
+        assert_eq!(blocks, vec!["a\nb".to_string(), "c".to_string()]);
+    }
+
+    #[test]
+    fn parse_grid_reports_dimensions() {
+        let (grid, (rows, cols)) = parse_grid("ab\ncde\n");
+        assert_eq!(rows, 2);
+        assert_eq!(cols, 3);
+        assert_eq!(grid[1], vec!['c', 'd', 'e']);
+    }
+
+    #[test]
+    fn parse_fields_extracts_pair() {
+        let [x, y]: [i64; 2] = parse_fields("3,-4", ',').unwrap();
+        assert_eq!((x, y), (3, -4));
+    }
+
+    #[test]
+    fn parse_fields_rejects_wrong_arity() {
+        assert!(parse_fields::<i64, 2>("1,2,3", ',').is_err());
+    }
+}
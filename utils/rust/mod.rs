@@ -1,8 +1,20 @@
 pub mod sorting;
 pub mod searching;
+pub mod bench;
 pub mod dsa;
+pub mod fetch;
+pub mod io;
+pub mod runner;
+pub mod solution;
 
 // Re-export commonly used items
 pub use sorting::*;
 pub use searching::*;
-pub use dsa::*; 
\ No newline at end of file
+pub use dsa::*;
+
+/// Items a day solution pulls in with `use aoc::prelude::*;`.
+pub mod prelude {
+    pub use crate::aoc_main;
+    pub use crate::solution::{read_example, read_input_adjacent, Solution};
+    pub use anyhow::{Context, Result};
+} 
\ No newline at end of file
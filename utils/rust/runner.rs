@@ -0,0 +1,425 @@
+//! A central dispatcher that runs any subset of [`Solution`] days from one
+//! binary, replacing the model where each day was a standalone `main` runnable
+//! only from inside its own directory.
+//!
+//! Days are type-erased behind [`ErasedSolution`] and stored in a [`Registry`]
+//! keyed by `(year, day)`, so adding a day means registering one entry rather
+//! than duplicating a whole `main`. The accompanying `aoc` binary parses CLI
+//! args like `--year 2025 --days 1..=25` or `--days 1,3,7` (bare invocation
+//! runs everything), locates each day's `input.txt`, executes the matching
+//! `part1`/`part2`, and prints a summary table of answers and per-part elapsed
+//! time.
+
+use crate::solution::Solution;
+use anyhow::{Context, Result};
+use std::marker::PhantomData;
+use std::path::PathBuf;
+use std::time::{Duration, Instant};
+
+/// Object-safe view of a [`Solution`] so differently-typed days can share one
+/// registry. Answers are rendered to `String` via their `Display` impls.
+pub trait ErasedSolution {
+    /// The puzzle year, from [`Solution::YEAR`].
+    fn year(&self) -> u16;
+    /// The puzzle day, from [`Solution::DAY`].
+    fn day(&self) -> u8;
+    /// Runs part 1, returning its answer rendered as a string.
+    fn part1(&self, input: &str) -> Result<String>;
+    /// Runs part 2, returning its answer rendered as a string.
+    fn part2(&self, input: &str) -> Result<String>;
+}
+
+/// Zero-sized adapter carrying a concrete `S: Solution` as an [`ErasedSolution`].
+struct Erased<S>(PhantomData<S>);
+
+impl<S: Solution> ErasedSolution for Erased<S> {
+    fn year(&self) -> u16 {
+        S::YEAR
+    }
+
+    fn day(&self) -> u8 {
+        S::DAY
+    }
+
+    fn part1(&self, input: &str) -> Result<String> {
+        Ok(S::part1(input).context("part 1 failed")?.to_string())
+    }
+
+    fn part2(&self, input: &str) -> Result<String> {
+        Ok(S::part2(input).context("part 2 failed")?.to_string())
+    }
+}
+
+/// Boxes a day's [`Solution`] impl for storage in a [`Registry`].
+pub fn erase<S: Solution + 'static>() -> Box<dyn ErasedSolution> {
+    Box::new(Erased::<S>(PhantomData))
+}
+
+/// Which days to run within a selected year.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DaySpec {
+    /// Every registered day (bare invocation, or no `--days`).
+    All,
+    /// An inclusive range such as `1..=25` or `1..25`.
+    Range(u8, u8),
+    /// An explicit list such as `1,3,7`.
+    List(Vec<u8>),
+}
+
+impl DaySpec {
+    /// Parses the `--days` argument.
+    ///
+    /// Accepts `1..=25` (inclusive), `1..25` (exclusive upper bound), a
+    /// comma-separated list `1,3,7`, or a single day `5`.
+    pub fn parse(spec: &str) -> Result<Self> {
+        let spec = spec.trim();
+        if let Some((lo, hi)) = spec.split_once("..=") {
+            let lo = lo.trim().parse().with_context(|| format!("bad range start {lo:?}"))?;
+            let hi = hi.trim().parse().with_context(|| format!("bad range end {hi:?}"))?;
+            return Ok(DaySpec::Range(lo, hi));
+        }
+        if let Some((lo, hi)) = spec.split_once("..") {
+            let lo: u8 = lo.trim().parse().with_context(|| format!("bad range start {lo:?}"))?;
+            let hi: u8 = hi.trim().parse().with_context(|| format!("bad range end {hi:?}"))?;
+            return Ok(DaySpec::Range(lo, hi.saturating_sub(1)));
+        }
+        if spec.contains(',') {
+            let days = spec
+                .split(',')
+                .map(|d| d.trim().parse().with_context(|| format!("bad day {d:?}")))
+                .collect::<Result<_>>()?;
+            return Ok(DaySpec::List(days));
+        }
+        let day = spec.parse().with_context(|| format!("bad day {spec:?}"))?;
+        Ok(DaySpec::List(vec![day]))
+    }
+
+    /// Whether `day` is selected by this spec.
+    pub fn matches(&self, day: u8) -> bool {
+        match self {
+            DaySpec::All => true,
+            DaySpec::Range(lo, hi) => *lo <= day && day <= *hi,
+            DaySpec::List(days) => days.contains(&day),
+        }
+    }
+}
+
+/// A table of all known day solutions, keyed by `(year, day)`.
+#[derive(Default)]
+pub struct Registry {
+    entries: Vec<Box<dyn ErasedSolution>>,
+}
+
+impl Registry {
+    /// Creates an empty registry.
+    pub fn new() -> Self {
+        Registry::default()
+    }
+
+    /// Registers a single day's [`Solution`] impl.
+    pub fn register<S: Solution + 'static>(&mut self) -> &mut Self {
+        self.entries.push(erase::<S>());
+        self
+    }
+
+    /// Returns the registered days matching `year` (or any year when `None`)
+    /// and `spec`, sorted by `(year, day)`.
+    pub fn select(&self, year: Option<u16>, spec: &DaySpec) -> Vec<&dyn ErasedSolution> {
+        let mut selected: Vec<&dyn ErasedSolution> = self
+            .entries
+            .iter()
+            .map(|e| e.as_ref())
+            .filter(|e| year.is_none_or(|y| e.year() == y))
+            .filter(|e| spec.matches(e.day()))
+            .collect();
+        selected.sort_by_key(|e| (e.year(), e.day()));
+        selected
+    }
+
+    /// Resolves, reads, runs, and prints every selected day.
+    pub fn run(&self, year: Option<u16>, spec: &DaySpec) -> Result<()> {
+        let selected = self.select(year, spec);
+        if selected.is_empty() {
+            println!("No registered days matched the selection.");
+            return Ok(());
+        }
+
+        println!(
+            "{:<6} {:<4} {:<20} {:<12} {:<20} {:<12}",
+            "year", "day", "part1", "time", "part2", "time"
+        );
+        for entry in selected {
+            let path = input_path(entry.year(), entry.day())?;
+            let input = crate::fetch::ensure_input(entry.year(), entry.day(), &path)?;
+
+            let t1 = Instant::now();
+            let p1 = match entry.part1(&input) {
+                Ok(p1) => p1,
+                Err(e) => {
+                    println!("{:<6} {:<4} error: {e:#}", entry.year(), entry.day());
+                    continue;
+                }
+            };
+            let e1 = t1.elapsed();
+
+            let t2 = Instant::now();
+            let p2 = match entry.part2(&input) {
+                Ok(p2) => p2,
+                Err(e) => {
+                    println!("{:<6} {:<4} error: {e:#}", entry.year(), entry.day());
+                    continue;
+                }
+            };
+            let e2 = t2.elapsed();
+
+            println!(
+                "{:<6} {:<4} {:<20} {:<12} {:<20} {:<12}",
+                entry.year(),
+                entry.day(),
+                p1,
+                format_duration(e1),
+                p2,
+                format_duration(e2),
+            );
+        }
+        Ok(())
+    }
+}
+
+impl Registry {
+    /// Benchmarks each selected day, timing both parts `runs` times and
+    /// printing min/mean/median wall-clock. Exposed behind the runner's
+    /// `--bench` flag.
+    pub fn bench(&self, year: Option<u16>, spec: &DaySpec, runs: usize) -> Result<()> {
+        let selected = self.select(year, spec);
+        if selected.is_empty() {
+            println!("No registered days matched the selection.");
+            return Ok(());
+        }
+
+        for entry in selected {
+            let path = input_path(entry.year(), entry.day())?;
+            let input = crate::fetch::ensure_input(entry.year(), entry.day(), &path)?;
+
+            let t1 = crate::bench::bench(runs, || entry.part1(&input));
+            let t2 = crate::bench::bench(runs, || entry.part2(&input));
+            println!(
+                "{} day {} part1: min {} mean {} median {} ({} runs)",
+                entry.year(),
+                entry.day(),
+                format_duration(t1.min),
+                format_duration(t1.mean),
+                format_duration(t1.median),
+                t1.runs,
+            );
+            println!(
+                "{} day {} part2: min {} mean {} median {} ({} runs)",
+                entry.year(),
+                entry.day(),
+                format_duration(t2.min),
+                format_duration(t2.mean),
+                format_duration(t2.median),
+                t2.runs,
+            );
+        }
+        Ok(())
+    }
+}
+
+/// The sentinel a day carries until it's declared solved. While present, the
+/// watcher stays on that day; removing it advances focus to the next day.
+pub const SENTINEL: &str = "// NOT SOLVED";
+
+/// Re-runs a day whenever its `input.txt` or source changes, advancing through
+/// the year's days one at a time.
+///
+/// Filesystem events are polled and debounced: a change is acted on only once
+/// the watched files have stopped changing for [`DEBOUNCE`]. Each cycle clears
+/// and redraws the result block. To avoid silently rolling onto the next
+/// unsolved day, the watcher only advances once the current day's source no
+/// longer contains the [`SENTINEL`] comment. The loop runs until every day from
+/// `day` onward is solved, or until interrupted (Ctrl-C).
+pub fn watch(registry: &Registry, year: u16, day: u8) -> Result<()> {
+    // Every day for the year, in order, from the requested starting day on.
+    let days: Vec<u8> = registry
+        .select(Some(year), &DaySpec::All)
+        .into_iter()
+        .map(|e| e.day())
+        .filter(|&d| d >= day)
+        .collect();
+
+    if days.is_empty() {
+        return Err(anyhow::anyhow!("no registered solution for {year} from day {day}"));
+    }
+
+    for current_day in days {
+        let entry = registry
+            .select(Some(year), &DaySpec::List(vec![current_day]))
+            .into_iter()
+            .next()
+            .with_context(|| format!("no registered solution for {year} day {current_day}"))?;
+
+        let input = input_path(year, current_day)?;
+        // The day's source sits next to its input under `<day>/rs/index.rs`.
+        let source = input
+            .parent()
+            .map(|dir| dir.join("rs").join("index.rs"))
+            .filter(|p| p.exists());
+
+        let watched: Vec<PathBuf> = std::iter::once(input.clone()).chain(source.clone()).collect();
+        println!(
+            "watching {year} day {current_day}; edit {} and save to re-run.\nRemove the `{SENTINEL}` comment from the source to advance (Ctrl-C to stop).",
+            input.display()
+        );
+
+        let mut last = fingerprint(&watched);
+        redraw(entry, &input);
+        if !has_sentinel(source.as_deref()) {
+            println!("day {current_day} has no `{SENTINEL}`; treating as solved.");
+            continue;
+        }
+
+        loop {
+            std::thread::sleep(POLL_INTERVAL);
+            let current = fingerprint(&watched);
+            if current == last {
+                continue;
+            }
+            // Debounce: wait for the files to settle before re-running. Compare
+            // each snapshot against the previous one, not the first post-change
+            // snapshot — mtimes only increase, so a save landing mid-window
+            // would otherwise never match and spin forever.
+            let mut prev = current;
+            loop {
+                std::thread::sleep(DEBOUNCE);
+                let settled = fingerprint(&watched);
+                if settled == prev {
+                    break;
+                }
+                prev = settled;
+            }
+            last = fingerprint(&watched);
+            redraw(entry, &input);
+
+            if !has_sentinel(source.as_deref()) {
+                println!("`{SENTINEL}` removed — day {current_day} solved, advancing.");
+                break;
+            }
+        }
+    }
+
+    println!("all watched days solved.");
+    Ok(())
+}
+
+/// Whether the day's source still carries the [`SENTINEL`]. A missing source is
+/// treated as solved so days without a source file don't stall the watcher.
+fn has_sentinel(source: Option<&std::path::Path>) -> bool {
+    match source {
+        Some(path) => std::fs::read_to_string(path)
+            .map(|s| s.contains(SENTINEL))
+            .unwrap_or(false),
+        None => false,
+    }
+}
+
+/// How often the watcher polls for changes.
+const POLL_INTERVAL: Duration = Duration::from_millis(200);
+/// How long the watched files must be quiet before a re-run.
+const DEBOUNCE: Duration = Duration::from_millis(150);
+
+/// Collects the modified-time of each watched file, so a change is a cheap
+/// comparison rather than a content diff.
+fn fingerprint(paths: &[PathBuf]) -> Vec<Option<std::time::SystemTime>> {
+    paths
+        .iter()
+        .map(|p| std::fs::metadata(p).and_then(|m| m.modified()).ok())
+        .collect()
+}
+
+/// Clears the screen and prints a fresh result block for one day.
+fn redraw(entry: &dyn ErasedSolution, input: &std::path::Path) {
+    // Clear screen and move the cursor home.
+    print!("\x1b[2J\x1b[H");
+    let contents = match std::fs::read_to_string(input) {
+        Ok(contents) => contents,
+        Err(e) => {
+            println!("failed to read {}: {e}", input.display());
+            return;
+        }
+    };
+
+    let t1 = Instant::now();
+    match entry.part1(&contents) {
+        Ok(p1) => println!("Part 1: {p1}  ({})", format_duration(t1.elapsed())),
+        Err(e) => println!("Part 1: error: {e:#}"),
+    }
+    let t2 = Instant::now();
+    match entry.part2(&contents) {
+        Ok(p2) => println!("Part 2: {p2}  ({})", format_duration(t2.elapsed())),
+        Err(e) => println!("Part 2: error: {e:#}"),
+    }
+}
+
+/// Resolves `challenges/<year>/<day>/input.txt` relative to the repository root.
+///
+/// The root defaults to two levels above the library's `CARGO_MANIFEST_DIR`
+/// (`utils/rust`), and can be overridden with the `AOC_ROOT` environment
+/// variable so the binary works from any working directory.
+pub fn input_path(year: u16, day: u8) -> Result<PathBuf> {
+    let root = match std::env::var_os("AOC_ROOT") {
+        Some(root) => PathBuf::from(root),
+        None => PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+            .parent()
+            .and_then(|p| p.parent())
+            .context("Failed to locate repository root")?
+            .to_path_buf(),
+    };
+    Ok(root
+        .join("challenges")
+        .join(year.to_string())
+        .join(day.to_string())
+        .join("input.txt"))
+}
+
+/// Formats a duration compactly for the summary table (e.g. `1.23ms`).
+fn format_duration(d: Duration) -> String {
+    let micros = d.as_micros();
+    if micros < 1_000 {
+        format!("{micros}µs")
+    } else if micros < 1_000_000 {
+        format!("{:.2}ms", micros as f64 / 1_000.0)
+    } else {
+        format!("{:.2}s", micros as f64 / 1_000_000.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_inclusive_range() {
+        assert_eq!(DaySpec::parse("1..=25").unwrap(), DaySpec::Range(1, 25));
+    }
+
+    #[test]
+    fn parses_exclusive_range() {
+        assert_eq!(DaySpec::parse("1..25").unwrap(), DaySpec::Range(1, 24));
+    }
+
+    #[test]
+    fn parses_list_and_single() {
+        assert_eq!(DaySpec::parse("1,3,7").unwrap(), DaySpec::List(vec![1, 3, 7]));
+        assert_eq!(DaySpec::parse("5").unwrap(), DaySpec::List(vec![5]));
+    }
+
+    #[test]
+    fn matches_respects_bounds() {
+        let range = DaySpec::parse("3..=5").unwrap();
+        assert!(!range.matches(2));
+        assert!(range.matches(4));
+        assert!(!range.matches(6));
+        assert!(DaySpec::All.matches(17));
+    }
+}
@@ -0,0 +1,199 @@
+//! A `Solution` trait that replaces the copy-pasted per-day scaffolding.
+//!
+//! Every day under `challenges/<year>/<day>/rs` used to re-declare an identical
+//! `InputConfig`, `read_input`, `main`, and `tests` module, differing only in the
+//! bodies of `part1`/`part2` (which even disagreed on return type). This module
+//! hoists that shared machinery behind a single trait so a day reduces to a tiny
+//! struct impl:
+//!
+//! ```ignore
+//! use aoc::prelude::*;
+//!
+//! struct Day1;
+//!
+//! impl Solution for Day1 {
+//!     const YEAR: u16 = 2025;
+//!     const DAY: u8 = 1;
+//!     type Answer1 = u32;
+//!     type Answer2 = u32;
+//!
+//!     fn part1(input: &str) -> Result<Self::Answer1> { /* ... */ }
+//!     fn part2(input: &str) -> Result<Self::Answer2> { /* ... */ }
+//! }
+//!
+//! aoc_main!(Day1);
+//! ```
+//!
+//! Parts return typed answers (`u64`, `String`, ...) with `anyhow` error
+//! propagation instead of panicking on `parse().unwrap()`.
+
+use anyhow::{Context, Result};
+use log::LevelFilter;
+use simple_logger::SimpleLogger;
+use std::fmt::Display;
+use std::fs;
+use std::path::PathBuf;
+use std::time::Instant;
+
+/// A single day's puzzle solution.
+///
+/// Implementors declare which puzzle they solve via the `YEAR`/`DAY` constants
+/// and which answer types each part produces. Both answer types only need to be
+/// `Display` so a day can return a `u64` from one part and a `String` from the
+/// other without the caller caring.
+pub trait Solution {
+    /// The Advent of Code year this day belongs to.
+    const YEAR: u16;
+    /// The day number within [`Self::YEAR`].
+    const DAY: u8;
+
+    /// The value produced by [`Solution::part1`].
+    type Answer1: Display;
+    /// The value produced by [`Solution::part2`].
+    type Answer2: Display;
+
+    /// Solves part 1 of the puzzle, propagating parse/IO failures as `anyhow`
+    /// errors rather than panicking.
+    fn part1(input: &str) -> Result<Self::Answer1>;
+
+    /// Solves part 2 of the puzzle, propagating parse/IO failures as `anyhow`
+    /// errors rather than panicking.
+    fn part2(input: &str) -> Result<Self::Answer2>;
+
+    /// Runs both parts against `input` and prints labeled results.
+    ///
+    /// Each day inherits this default. The `main` generated by [`aoc_main!`]
+    /// goes through the free function [`run`] instead, so overriding this
+    /// method does not change what `main` does.
+    fn run(input: &str) -> Result<()> {
+        println!(
+            "Part 1: {}",
+            Self::part1(input).context("part 1 failed")?
+        );
+        println!(
+            "Part 2: {}",
+            Self::part2(input).context("part 2 failed")?
+        );
+        Ok(())
+    }
+}
+
+/// A located input file, distinguishing the real puzzle input from a named
+/// example input.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ResolvedInput {
+    /// The real puzzle input (`input.txt`).
+    Puzzle(PathBuf),
+    /// A named example input, e.g. `example1` for `examples/example1.txt`.
+    Example { name: String, path: PathBuf },
+}
+
+impl ResolvedInput {
+    /// The located path, regardless of kind.
+    pub fn path(&self) -> &PathBuf {
+        match self {
+            ResolvedInput::Puzzle(path) => path,
+            ResolvedInput::Example { path, .. } => path,
+        }
+    }
+}
+
+/// Resolves a day's input by searching a prioritized list of glob patterns,
+/// preferring the real puzzle input over any example.
+///
+/// `manifest_dir` is the day's `CARGO_MANIFEST_DIR` (i.e.
+/// `challenges/<year>/<day>/rs`). Patterns are searched in order:
+/// `../input.txt`, `../examples/*.txt`, `./input*.txt`.
+pub fn resolve_input(manifest_dir: &str) -> Result<ResolvedInput> {
+    let manifest = PathBuf::from(manifest_dir);
+    let parent = manifest.parent().context("Failed to get parent directory")?;
+
+    // The real puzzle input wins outright.
+    let puzzle = parent.join("input.txt");
+    if puzzle.exists() {
+        return Ok(ResolvedInput::Puzzle(puzzle));
+    }
+
+    let patterns = [
+        parent.join("examples").join("*.txt"),
+        manifest.join("input*.txt"),
+    ];
+    for pattern in patterns {
+        let pattern = pattern.to_string_lossy();
+        for entry in glob::glob(&pattern).with_context(|| format!("bad glob {pattern:?}"))? {
+            let path = entry.with_context(|| format!("while globbing {pattern:?}"))?;
+            let name = path
+                .file_stem()
+                .map(|s| s.to_string_lossy().into_owned())
+                .unwrap_or_default();
+            return Ok(ResolvedInput::Example { name, path });
+        }
+    }
+
+    Err(anyhow::anyhow!(
+        "Could not find any input. Searched input.txt, examples/*.txt and input*.txt under {:?}",
+        parent
+    ))
+}
+
+/// Reads a day's input, preferring the real puzzle input and falling back to
+/// the first example found.
+///
+/// `manifest_dir` is the day's `CARGO_MANIFEST_DIR`; the puzzle input lives one
+/// level up in `challenges/<year>/<day>/input.txt`.
+pub fn read_input_adjacent(manifest_dir: &str) -> Result<String> {
+    let resolved = resolve_input(manifest_dir)?;
+    let path = resolved.path();
+    fs::read_to_string(path).with_context(|| format!("Failed to read input from {path:?}"))
+}
+
+/// Reads a named example input (e.g. `example1` → `../examples/example1.txt`),
+/// so a test module can assert exact expected answers instead of weak
+/// `result > 0` checks.
+pub fn read_example(manifest_dir: &str, name: &str) -> Result<String> {
+    let parent = PathBuf::from(manifest_dir)
+        .parent()
+        .context("Failed to get parent directory")?
+        .to_path_buf();
+    let path = parent.join("examples").join(format!("{name}.txt"));
+    fs::read_to_string(&path).with_context(|| format!("Failed to read example from {path:?}"))
+}
+
+/// Blanket driver for a single day: initializes logging, locates the day's
+/// input from its `YEAR`/`DAY`, times both parts, and prints labeled results.
+///
+/// This is the shared entry point each day's `main` delegates to, so a day
+/// carries no input-location, logging, or timing boilerplate of its own.
+pub fn run<S: Solution>() -> Result<()> {
+    // Best-effort logging init; a day may be invoked more than once in-process
+    // (e.g. from the runner), so a prior init is not an error.
+    let _ = SimpleLogger::new().with_level(LevelFilter::Info).init();
+
+    // Read the cached input, downloading and caching it on first use if absent.
+    let path = crate::runner::input_path(S::YEAR, S::DAY)?;
+    let input = crate::fetch::ensure_input(S::YEAR, S::DAY, &path)?;
+
+    println!("Year {} Day {}", S::YEAR, S::DAY);
+
+    let t1 = Instant::now();
+    let p1 = S::part1(&input).context("part 1 failed")?;
+    println!("Part 1: {p1} ({:?})", t1.elapsed());
+
+    let t2 = Instant::now();
+    let p2 = S::part2(&input).context("part 2 failed")?;
+    println!("Part 2: {p2} ({:?})", t2.elapsed());
+
+    Ok(())
+}
+
+/// Generates a `main` that runs a day's [`Solution`] impl through [`run`].
+///
+/// This is the blanket replacement for the copy-pasted per-day `main`.
+#[macro_export]
+macro_rules! aoc_main {
+    ($solver:ty) => {
+        fn main() -> ::anyhow::Result<()> {
+            $crate::solution::run::<$solver>()
+        }
+    };
+}